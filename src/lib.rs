@@ -1,12 +1,18 @@
 use std::future::Future;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use bevy_app::{App, Plugin, Update};
+use bevy_app::{App, AppExit, Last, Plugin, Update};
+use bevy_ecs::event::{EventCursor, Events};
 use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy_ecs::{prelude::World, resource::Resource};
 
-use tokio::{runtime::Runtime, task::JoinHandle};
+use futures_util::future::{FutureExt, RemoteHandle};
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::{runtime::Runtime, task::JoinHandle, task::LocalSet};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_futures::spawn_local;
 
 /// A re-export of the tokio version used by this crate.
 pub use tokio;
@@ -31,35 +37,87 @@ impl UpdateTicks {
 /// The Bevy [`Plugin`] which sets up the [`TokioTasksRuntime`] Bevy resource and registers
 /// the [`tick_runtime_update`] exclusive system.
 pub struct TokioTasksPlugin {
-    /// Callback which is used to create a Tokio runtime when the plugin is installed. The
-    /// default value for this field configures a multi-threaded [`Runtime`] with IO and timer
-    /// functionality enabled if building for non-wasm32 architectures. On wasm32 the current-thread
-    /// scheduler is used instead.
-    pub make_runtime: Box<dyn Fn() -> Runtime + Send + Sync + 'static>,
+    /// Escape hatch callback used to create the Tokio runtime when the plugin is installed,
+    /// for configuration that [`worker_threads`](Self::worker_threads), [`thread_name`](Self::thread_name),
+    /// [`thread_stack_size`](Self::thread_stack_size), [`enable_io`](Self::enable_io) and
+    /// [`enable_time`](Self::enable_time) don't cover. When `Some`, this entirely replaces the typed
+    /// fields below - none of them are applied. Defaults to `None`. Not available on wasm32, where
+    /// futures are driven by `wasm_bindgen_futures` instead of a Tokio [`Runtime`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub make_runtime: Option<Box<dyn Fn() -> Runtime + Send + Sync + 'static>>,
+    /// The number of worker threads for the multi-thread [`Runtime`]. Mirrors
+    /// [`Builder::worker_threads`](tokio::runtime::Builder::worker_threads). Defaults to `None`, which
+    /// uses Tokio's own default (the number of CPUs). Ignored if [`make_runtime`](Self::make_runtime)
+    /// is set. Not available on wasm32.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub worker_threads: Option<usize>,
+    /// The prefix used to name the runtime's worker threads. Mirrors
+    /// [`Builder::thread_name`](tokio::runtime::Builder::thread_name). Defaults to `None`, which uses
+    /// Tokio's own default. Ignored if [`make_runtime`](Self::make_runtime) is set. Not available on
+    /// wasm32.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub thread_name: Option<String>,
+    /// The stack size, in bytes, for the runtime's worker threads. Mirrors
+    /// [`Builder::thread_stack_size`](tokio::runtime::Builder::thread_stack_size). Defaults to `None`,
+    /// which uses Tokio's own default. Ignored if [`make_runtime`](Self::make_runtime) is set. Not
+    /// available on wasm32.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub thread_stack_size: Option<usize>,
+    /// Whether to enable the runtime's IO driver, via
+    /// [`Builder::enable_io`](tokio::runtime::Builder::enable_io). Defaults to `true`. Ignored if
+    /// [`make_runtime`](Self::make_runtime) is set. Not available on wasm32.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub enable_io: bool,
+    /// Whether to enable the runtime's time driver, via
+    /// [`Builder::enable_time`](tokio::runtime::Builder::enable_time). Defaults to `true`. Ignored if
+    /// [`make_runtime`](Self::make_runtime) is set. Not available on wasm32.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub enable_time: bool,
     /// The [`ScheduleLabel`] during which the [`tick_runtime_update`] function will be executed.
     /// The default value for this field is [`Update`].
     pub schedule_label: InternedScheduleLabel,
+    /// How long to wait for in-flight background tasks to finish once an [`AppExit`] event is
+    /// observed, before the Tokio worker threads are forcibly stopped. Passed directly to
+    /// [`Runtime::shutdown_timeout`]. Defaults to 5 seconds. Not available on wasm32, which has
+    /// no worker threads to stop.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub shutdown_timeout: Duration,
+    /// Caps how many queued [`run_on_main_thread`](TaskContext::run_on_main_thread) callbacks are run per
+    /// Bevy update tick, so a burst of background tasks can't spike frame time by all running in the same
+    /// frame. Leftover callbacks stay queued in FIFO order and run on subsequent ticks. Defaults to `None`,
+    /// which preserves the historical drain-everything-every-tick behavior.
+    pub max_main_thread_callbacks_per_tick: Option<usize>,
+    /// Caps how much wall-clock time is spent running queued [`run_on_main_thread`](TaskContext::run_on_main_thread)
+    /// callbacks per Bevy update tick. Checked with [`Instant::now`] between callbacks, so an individual
+    /// callback is never interrupted mid-execution. Defaults to `None`, which preserves the historical
+    /// drain-everything-every-tick behavior.
+    pub max_main_thread_callback_duration: Option<Duration>,
 }
 
 impl Default for TokioTasksPlugin {
-    /// Configures the plugin to build a new Tokio [`Runtime`] with both IO and timer functionality
-    /// enabled. On the wasm32 architecture, the [`Runtime`] will be the current-thread runtime, on all other
-    /// architectures the [`Runtime`] will be the multi-thread runtime.
-    /// 
+    /// Configures the plugin to build a new multi-thread Tokio [`Runtime`] with both IO and timer
+    /// functionality enabled and Tokio's own defaults for worker thread count, naming and stack size.
+    ///
     /// The default schedule label is [`Update`].
     fn default() -> Self {
         Self {
-            make_runtime: Box::new(|| {
-                #[cfg(not(target_arch = "wasm32"))]
-                let mut runtime = tokio::runtime::Builder::new_multi_thread();
-                #[cfg(target_arch = "wasm32")]
-                let mut runtime = tokio::runtime::Builder::new_current_thread();
-                runtime.enable_all();
-                runtime
-                    .build()
-                    .expect("Failed to create Tokio runtime for background tasks")
-            }),
-            schedule_label: Update.intern()
+            #[cfg(not(target_arch = "wasm32"))]
+            make_runtime: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            worker_threads: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            thread_name: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            thread_stack_size: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            enable_io: true,
+            #[cfg(not(target_arch = "wasm32"))]
+            enable_time: true,
+            schedule_label: Update.intern(),
+            #[cfg(not(target_arch = "wasm32"))]
+            shutdown_timeout: Duration::from_secs(5),
+            max_main_thread_callbacks_per_tick: None,
+            max_main_thread_callback_duration: None,
         }
     }
 }
@@ -68,13 +126,56 @@ impl Plugin for TokioTasksPlugin {
     fn build(&self, app: &mut App) {
         let ticks = Arc::new(AtomicUsize::new(0));
         let (update_watch_tx, update_watch_rx) = tokio::sync::watch::channel(());
-        let runtime = (self.make_runtime)();
         app.insert_resource(UpdateTicks {
             ticks: ticks.clone(),
             update_watch_tx,
         });
-        app.insert_resource(TokioTasksRuntime::new(ticks, runtime, update_watch_rx));
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let runtime = match &self.make_runtime {
+                Some(make_runtime) => make_runtime(),
+                None => {
+                    let mut builder = tokio::runtime::Builder::new_multi_thread();
+                    if let Some(worker_threads) = self.worker_threads {
+                        builder.worker_threads(worker_threads);
+                    }
+                    if let Some(thread_name) = &self.thread_name {
+                        builder.thread_name(thread_name.clone());
+                    }
+                    if let Some(thread_stack_size) = self.thread_stack_size {
+                        builder.thread_stack_size(thread_stack_size);
+                    }
+                    if self.enable_io {
+                        builder.enable_io();
+                    }
+                    if self.enable_time {
+                        builder.enable_time();
+                    }
+                    builder
+                        .build()
+                        .expect("Failed to create Tokio runtime for background tasks")
+                }
+            };
+            app.insert_resource(TokioTasksRuntime::new(
+                ticks,
+                runtime,
+                update_watch_rx,
+                self.shutdown_timeout,
+                self.max_main_thread_callbacks_per_tick,
+                self.max_main_thread_callback_duration,
+            ));
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            app.insert_resource(TokioTasksRuntime::new(
+                ticks,
+                update_watch_rx,
+                self.max_main_thread_callbacks_per_tick,
+                self.max_main_thread_callback_duration,
+            ));
+        }
         app.add_systems(self.schedule_label, tick_runtime_update);
+        app.add_systems(Last, shutdown_runtime_on_app_exit);
     }
 }
 
@@ -99,9 +200,67 @@ pub fn tick_runtime_update(world: &mut World) {
     }
 }
 
+/// The Bevy exclusive system, registered in the [`Last`] schedule, which performs a graceful
+/// shutdown of the [`TokioTasksRuntime`] once an [`AppExit`] event has been observed. See
+/// [`TokioTasksRuntime::shutdown`] for the details of what happens during shutdown.
+pub fn shutdown_runtime_on_app_exit(world: &mut World) {
+    // Peek at `Events<AppExit>` with a fresh cursor, the same way `App::should_exit` does,
+    // instead of draining it - draining here would empty the shared event storage before
+    // the runner gets a chance to call `should_exit` in the same `Last` schedule, and the
+    // app would never observe its own exit.
+    let exited = match world.get_resource::<Events<AppExit>>() {
+        Some(app_exit_events) => EventCursor::default().read(app_exit_events).len() != 0,
+        None => false,
+    };
+    if !exited {
+        return;
+    }
+
+    if let Some(runtime) = world.remove_resource::<TokioTasksRuntime>() {
+        runtime.shutdown(world);
+    }
+}
+
 type MainThreadCallback = Box<dyn FnOnce(MainThreadContext) + Send + 'static>;
 
-/// The Bevy [`Resource`] which stores the Tokio [`Runtime`] and allows for spawning new
+/// The handle returned by [`TokioTasksRuntime::spawn_background_task`]. Wraps a [`JoinHandle`] on
+/// non-wasm32 targets and a [`RemoteHandle`] on wasm32 so that dropping/detaching behavior matches
+/// what each target natively supports, while awaiting the handle yields the task's `Output`
+/// directly on both, keeping call sites source-compatible across targets.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct BackgroundTaskHandle<Output>(JoinHandle<Output>);
+#[cfg(target_arch = "wasm32")]
+pub struct BackgroundTaskHandle<Output>(RemoteHandle<Output>);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<Output> Future for BackgroundTaskHandle<Output> {
+    type Output = Output;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Output> {
+        let inner = self.get_mut();
+        std::pin::Pin::new(&mut inner.0).poll(cx).map(|result| {
+            result.expect("background task panicked or was cancelled before finishing")
+        })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl<Output> Future for BackgroundTaskHandle<Output> {
+    type Output = Output;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Output> {
+        let inner = self.get_mut();
+        std::pin::Pin::new(&mut inner.0).poll(cx)
+    }
+}
+
+/// The Bevy [`Resource`] which stores the async executor and allows for spawning new
 /// background tasks.
 #[derive(Resource)]
 pub struct TokioTasksRuntime(Box<TokioTasksRuntimeInner>);
@@ -109,18 +268,64 @@ pub struct TokioTasksRuntime(Box<TokioTasksRuntimeInner>);
 /// The inner fields are boxed to reduce the cost of the every-frame move out of and back into
 /// the world in [`tick_runtime_update`].
 struct TokioTasksRuntimeInner {
+    #[cfg(not(target_arch = "wasm32"))]
     runtime: Runtime,
+    #[cfg(not(target_arch = "wasm32"))]
+    local_set: LocalSet,
+    /// The thread `local_set` was created on. `LocalSet` is thread-affine - its internal queue
+    /// is only safe to touch from this thread - so every access is checked against it at runtime
+    /// rather than relying solely on `LocalSet`'s own debug-only assertion, which is compiled out
+    /// in release builds.
+    #[cfg(not(target_arch = "wasm32"))]
+    local_set_owner: std::thread::ThreadId,
+    #[cfg(not(target_arch = "wasm32"))]
+    shutdown_timeout: Duration,
     ticks: Arc<AtomicUsize>,
     update_watch_rx: tokio::sync::watch::Receiver<()>,
     update_run_tx: tokio::sync::mpsc::UnboundedSender<MainThreadCallback>,
     update_run_rx: tokio::sync::mpsc::UnboundedReceiver<MainThreadCallback>,
+    max_main_thread_callbacks_per_tick: Option<usize>,
+    max_main_thread_callback_duration: Option<Duration>,
+}
+
+// SAFETY: `local_set` is `!Send`/`!Sync` because its internal queue must only ever be touched
+// from the thread that created it. Bevy requires every `Resource` to be `Send + Sync` for storage
+// purposes, so this `unsafe impl` lets the world hand `TokioTasksRuntimeInner` between threads -
+// but every function that actually touches `local_set` checks `local_set_owner` against
+// `std::thread::current().id()` first and panics on a mismatch, so the thread-affinity invariant
+// is enforced at runtime rather than assumed.
+#[cfg(not(target_arch = "wasm32"))]
+unsafe impl Send for TokioTasksRuntimeInner {}
+#[cfg(not(target_arch = "wasm32"))]
+unsafe impl Sync for TokioTasksRuntimeInner {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TokioTasksRuntimeInner {
+    /// Panics if called from a thread other than the one that created `local_set`, since driving
+    /// or spawning onto a `LocalSet` from any other thread is undefined behavior that `LocalSet`
+    /// itself only catches via a debug-only assertion.
+    fn assert_local_set_owner_thread(&self) {
+        let current = std::thread::current().id();
+        assert_eq!(
+            current, self.local_set_owner,
+            "TokioTasksRuntime's LocalSet was created on thread {:?} and must only be driven or \
+             spawned onto from that same thread, but this call is happening on thread {:?}. Make \
+             sure App::update (and therefore tick_runtime_update) always runs on the thread that \
+             added TokioTasksPlugin.",
+            self.local_set_owner, current
+        );
+    }
 }
 
 impl TokioTasksRuntime {
+    #[cfg(not(target_arch = "wasm32"))]
     fn new(
         ticks: Arc<AtomicUsize>,
         runtime: Runtime,
         update_watch_rx: tokio::sync::watch::Receiver<()>,
+        shutdown_timeout: Duration,
+        max_main_thread_callbacks_per_tick: Option<usize>,
+        max_main_thread_callback_duration: Option<Duration>,
     ) -> Self {
         let (update_run_tx, update_run_rx) = tokio::sync::mpsc::unbounded_channel();
 
@@ -130,23 +335,56 @@ impl TokioTasksRuntime {
             update_watch_rx,
             update_run_tx,
             update_run_rx,
+            local_set: LocalSet::new(),
+            local_set_owner: std::thread::current().id(),
+            shutdown_timeout,
+            max_main_thread_callbacks_per_tick,
+            max_main_thread_callback_duration,
+        }))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn new(
+        ticks: Arc<AtomicUsize>,
+        update_watch_rx: tokio::sync::watch::Receiver<()>,
+        max_main_thread_callbacks_per_tick: Option<usize>,
+        max_main_thread_callback_duration: Option<Duration>,
+    ) -> Self {
+        let (update_run_tx, update_run_rx) = tokio::sync::mpsc::unbounded_channel();
+
+        Self(Box::new(TokioTasksRuntimeInner {
+            ticks,
+            update_watch_rx,
+            update_run_tx,
+            update_run_rx,
+            max_main_thread_callbacks_per_tick,
+            max_main_thread_callback_duration,
         }))
     }
 
     /// Returns the Tokio [`Runtime`] on which background tasks are executed. You can specify
     /// how this is created by providing a custom [`make_runtime`](TokioTasksPlugin::make_runtime).
+    /// Not available on wasm32, where there is no Tokio [`Runtime`] - futures are driven by
+    /// `wasm_bindgen_futures::spawn_local` instead.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn runtime(&self) -> &Runtime {
         &self.0.runtime
     }
 
-    /// Spawn a task which will run on the background Tokio [`Runtime`] managed by this [`TokioTasksRuntime`]. The
-    /// background task is provided a [`TaskContext`] which allows it to do things like
+    /// Spawn a task which will run in the background. On non-wasm32 targets this runs on the Tokio
+    /// [`Runtime`] managed by this [`TokioTasksRuntime`] and detaches the task if the returned
+    /// [`BackgroundTaskHandle`] is dropped. On wasm32 the task is driven cooperatively on the browser's
+    /// event loop via `wasm_bindgen_futures::spawn_local` and dropping the handle cancels the task
+    /// instead, since wasm32 has no equivalent of a detached [`JoinHandle`]. Either way, awaiting the
+    /// handle yields the task's `Output` directly, so call sites stay source-compatible across targets.
+    /// The background task is provided a [`TaskContext`] which allows it to do things like
     /// [sleep for a given number of main thread updates](TaskContext::sleep_updates) or
     /// [invoke callbacks on the main Bevy thread](TaskContext::run_on_main_thread).
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn spawn_background_task<Task, Output, Spawnable>(
         &self,
         spawnable_task: Spawnable,
-    ) -> JoinHandle<Output>
+    ) -> BackgroundTaskHandle<Output>
     where
         Task: Future<Output = Output> + Send + 'static,
         Output: Send + 'static,
@@ -159,23 +397,236 @@ impl TokioTasksRuntime {
             update_run_tx: inner.update_run_tx.clone(),
         };
         let future = spawnable_task(context);
-        inner.runtime.spawn(future)
+        BackgroundTaskHandle(inner.runtime.spawn(future))
+    }
+
+    /// See the non-wasm32 documentation above. On wasm32, `Task` and `Spawnable` need not be `Send`
+    /// because `wasm_bindgen_futures::spawn_local` runs everything on the single browser thread.
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn_background_task<Task, Output, Spawnable>(
+        &self,
+        spawnable_task: Spawnable,
+    ) -> BackgroundTaskHandle<Output>
+    where
+        Task: Future<Output = Output> + 'static,
+        Output: 'static,
+        Spawnable: FnOnce(TaskContext) -> Task + 'static,
+    {
+        let inner = &self.0;
+        let context = TaskContext {
+            update_watch_rx: inner.update_watch_rx.clone(),
+            ticks: inner.ticks.clone(),
+            update_run_tx: inner.update_run_tx.clone(),
+        };
+        let future = spawnable_task(context);
+        let (remote, handle) = future.remote_handle();
+        spawn_local(remote);
+        BackgroundTaskHandle(handle)
+    }
+
+    /// Spawn a task which will run on the background Tokio [`Runtime`] managed by this [`TokioTasksRuntime`],
+    /// returning a [`RemoteHandle`] instead of a [`JoinHandle`]. Unlike [`spawn_background_task`](Self::spawn_background_task),
+    /// dropping the returned handle cancels the underlying task rather than detaching it, so storing the handle
+    /// in a Bevy [`Component`](bevy_ecs::component::Component) ties the task's lifetime to the owning entity.
+    /// Awaiting the handle yields the task's output, just like awaiting a [`JoinHandle`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_background_task_cancellable<Task, Output, Spawnable>(
+        &self,
+        spawnable_task: Spawnable,
+    ) -> RemoteHandle<Output>
+    where
+        Task: Future<Output = Output> + Send + 'static,
+        Output: Send + 'static,
+        Spawnable: FnOnce(TaskContext) -> Task + Send + 'static,
+    {
+        let inner = &self.0;
+        let context = TaskContext {
+            update_watch_rx: inner.update_watch_rx.clone(),
+            ticks: inner.ticks.clone(),
+            update_run_tx: inner.update_run_tx.clone(),
+        };
+        let future = spawnable_task(context);
+        let (remote, handle) = future.remote_handle();
+        inner.runtime.spawn(remote);
+        handle
+    }
+
+    /// On wasm32, [`spawn_background_task`](Self::spawn_background_task) already returns a cancel-on-drop
+    /// [`BackgroundTaskHandle`], so this is provided only to keep call sites source-compatible with
+    /// non-wasm32 targets and simply forwards to it.
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn_background_task_cancellable<Task, Output, Spawnable>(
+        &self,
+        spawnable_task: Spawnable,
+    ) -> BackgroundTaskHandle<Output>
+    where
+        Task: Future<Output = Output> + 'static,
+        Output: 'static,
+        Spawnable: FnOnce(TaskContext) -> Task + 'static,
+    {
+        self.spawn_background_task(spawnable_task)
+    }
+
+    /// Spawn a `!Send` task onto a [`LocalSet`] owned by this [`TokioTasksRuntime`]. Use this for futures
+    /// built on non-`Send` types such as `Rc`/`RefCell` or client libraries that aren't thread-safe, which
+    /// can't be scheduled with [`spawn_background_task`](Self::spawn_background_task). The `LocalSet` only
+    /// makes progress while [`execute_main_thread_work`](Self::execute_main_thread_work) drives it, so local
+    /// tasks advance one step per Bevy update tick alongside queued main-thread callbacks. Must only be
+    /// called from the thread that added [`TokioTasksPlugin`] (e.g. from a normal or exclusive Bevy system
+    /// running on the thread driving `App::update`), since the underlying `LocalSet` is thread-affine -
+    /// calling this from any other thread panics. Returns a [`BackgroundTaskHandle`] so awaiting it
+    /// yields the task's `Output` directly, matching [`spawn_background_task`](Self::spawn_background_task)
+    /// and its wasm32 counterpart below.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_local_task<Task, Output, Spawnable>(
+        &self,
+        spawnable_task: Spawnable,
+    ) -> BackgroundTaskHandle<Output>
+    where
+        Task: Future<Output = Output> + 'static,
+        Output: 'static,
+        Spawnable: FnOnce(TaskContext) -> Task + 'static,
+    {
+        let inner = &self.0;
+        inner.assert_local_set_owner_thread();
+        let context = TaskContext {
+            update_watch_rx: inner.update_watch_rx.clone(),
+            ticks: inner.ticks.clone(),
+            update_run_tx: inner.update_run_tx.clone(),
+        };
+        let future = spawnable_task(context);
+        BackgroundTaskHandle(inner.local_set.spawn_local(future))
+    }
+
+    /// On wasm32 every task is already `!Send`-friendly, since `wasm_bindgen_futures::spawn_local` runs
+    /// everything on the single browser thread. This is provided only to keep call sites source-compatible
+    /// with non-wasm32 targets and simply forwards to [`spawn_background_task`](Self::spawn_background_task).
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn_local_task<Task, Output, Spawnable>(
+        &self,
+        spawnable_task: Spawnable,
+    ) -> BackgroundTaskHandle<Output>
+    where
+        Task: Future<Output = Output> + 'static,
+        Output: 'static,
+        Spawnable: FnOnce(TaskContext) -> Task + 'static,
+    {
+        self.spawn_background_task(spawnable_task)
     }
 
     /// Execute all of the requested runnables on the main thread.
+    /// Stops early once the per-tick budget configured on [`TokioTasksPlugin`] is used up; the
+    /// remaining runnables stay queued for the next tick.
+    #[cfg(not(target_arch = "wasm32"))]
     pub(crate) fn execute_main_thread_work(&mut self, world: &mut World, current_tick: usize) {
+        self.0.assert_local_set_owner_thread();
         // Running this single future which yields once allows the runtime to process tasks
         // if the runtime is a current_thread runtime. If its a multi-thread runtime then
-        // this isn't necessary but is harmless.
-        self.0.runtime.block_on(async {
+        // this isn't necessary but is harmless. Driving it with `local_set.run_until` also gives
+        // any tasks spawned with `spawn_local_task` a chance to make progress this tick.
+        let local_set = &self.0.local_set;
+        self.0.runtime.block_on(local_set.run_until(async {
             tokio::task::yield_now().await;
-        });
+        }));
+        self.drain_main_thread_work(world, current_tick);
+    }
+
+    /// Drains the requested runnables onto the main thread. Tasks spawned with
+    /// [`spawn_background_task`](Self::spawn_background_task) are already being driven
+    /// cooperatively by the browser's event loop via `wasm_bindgen_futures::spawn_local`, so there
+    /// is nothing to step here beyond the drain itself.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn execute_main_thread_work(&mut self, world: &mut World, current_tick: usize) {
+        self.drain_main_thread_work(world, current_tick);
+    }
+
+    /// Runs queued [`run_on_main_thread`](TaskContext::run_on_main_thread) callbacks against `world`,
+    /// in FIFO order, until the queue is empty or the budget configured via
+    /// [`TokioTasksPlugin::max_main_thread_callbacks_per_tick`] /
+    /// [`TokioTasksPlugin::max_main_thread_callback_duration`] is exhausted. Leftover callbacks stay
+    /// queued and are picked up on the next call. With no budget configured, this drains everything.
+    /// A budget of zero callbacks, or an already-elapsed duration, runs nothing this call.
+    fn drain_main_thread_work(&mut self, world: &mut World, current_tick: usize) {
+        if self.0.max_main_thread_callbacks_per_tick == Some(0) {
+            return;
+        }
+        let deadline = self
+            .0
+            .max_main_thread_callback_duration
+            .map(|duration| Instant::now() + duration);
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return;
+        }
+        let mut callbacks_run = 0usize;
         while let Ok(runnable) = self.0.update_run_rx.try_recv() {
             let context = MainThreadContext {
                 world,
                 current_tick,
             };
             runnable(context);
+            callbacks_run += 1;
+
+            if let Some(max_callbacks) = self.0.max_main_thread_callbacks_per_tick {
+                if callbacks_run >= max_callbacks {
+                    break;
+                }
+            }
+            if let Some(deadline) = deadline {
+                if Instant::now() >= deadline {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Performs a graceful shutdown of this runtime in response to an [`AppExit`] event. The
+    /// main-thread queue is closed first, so no new main-thread work is accepted after this point:
+    /// a task that calls [`run_on_main_thread`](TaskContext::run_on_main_thread) afterwards panics.
+    /// Callbacks that were already queued are run against `world` one final time, and then the
+    /// underlying [`Runtime`] is torn down with [`Runtime::shutdown_timeout`], giving background
+    /// tasks up to [`TokioTasksPlugin::shutdown_timeout`] to finish before whatever worker threads
+    /// remain are forcibly stopped.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn shutdown(self, world: &mut World) {
+        self.0.assert_local_set_owner_thread();
+        let TokioTasksRuntimeInner {
+            runtime,
+            ticks,
+            mut update_run_rx,
+            shutdown_timeout,
+            ..
+        } = *self.0;
+        let current_tick = ticks.load(Ordering::SeqCst);
+        update_run_rx.close();
+        while let Ok(runnable) = update_run_rx.try_recv() {
+            let context = MainThreadContext {
+                world,
+                current_tick,
+            };
+            runnable(context);
+        }
+        runtime.shutdown_timeout(shutdown_timeout);
+    }
+
+    /// Performs a graceful shutdown of this runtime in response to an [`AppExit`] event. No new
+    /// main-thread work is accepted after this point: whatever is already queued via
+    /// [`run_on_main_thread`](TaskContext::run_on_main_thread) is drained one final time so those
+    /// callbacks still get a chance to touch `world`. There are no worker threads to stop on
+    /// wasm32, so this is the entire shutdown sequence.
+    #[cfg(target_arch = "wasm32")]
+    fn shutdown(self, world: &mut World) {
+        let TokioTasksRuntimeInner {
+            ticks,
+            mut update_run_rx,
+            ..
+        } = *self.0;
+        let current_tick = ticks.load(Ordering::SeqCst);
+        while let Ok(runnable) = update_run_rx.try_recv() {
+            let context = MainThreadContext {
+                world,
+                current_tick,
+            };
+            runnable(context);
         }
     }
 }